@@ -1,19 +1,69 @@
 use std::borrow::BorrowMut;
+use std::sync::Arc;
 
 use anyhow::anyhow;
 use borsh::BorshDeserialize;
-use solana_client::rpc_config::RpcSendTransactionConfig;
+use futures_util::StreamExt;
+use futures_util::stream::FuturesUnordered;
+use serde::Deserialize;
+use solana_client::connection_cache::{ConnectionCache, ConnectionManager, ConnectionPool, NewConnectionConfig};
+use solana_client::rpc_config::{RpcSendTransactionConfig, RpcSignatureSubscribeConfig};
+use solana_client::rpc_response::RpcSignatureResult;
+use solana_client::tpu_client::{TpuClient, TpuClientConfig, DEFAULT_TPU_CONNECTION_POOL_SIZE};
 use solana_program::instruction::{Instruction, AccountMeta};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use solana_cli_config::{Config, CONFIG_FILE};
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_sdk::account_utils::StateMut;
 use solana_sdk::commitment_config::CommitmentConfig;
-use solana_sdk::signature::{Signer, read_keypair_file, Keypair};
+use solana_sdk::hash::Hash;
+use solana_sdk::nonce::state::{State as NonceState, Versions as NonceVersions};
+use solana_sdk::signature::{Signature, Signer, read_keypair_file, Keypair};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::system_instruction;
 use solana_sdk::transaction::Transaction;
 use vaults::state::Vault;
 use solana_program::pubkey;
 use solana_program::pubkey::Pubkey;
 
+// How the signed transaction is delivered to the cluster.
+#[derive(Clone, ValueEnum)]
+enum SubmitMode {
+    /// Send through the configured JSON-RPC endpoint (default).
+    Rpc,
+    /// Push straight to the current/upcoming slot leaders over QUIC/UDP.
+    Tpu,
+}
+
+// Whether (and how) to simulate the transaction before it is broadcast.
+#[derive(Clone, ValueEnum, PartialEq, Eq)]
+enum SimulateMode {
+    /// Skip simulation entirely (default).
+    Off,
+    /// Simulate first; abort the iteration with a diagnostic if simulation fails.
+    Preflight,
+    /// Simulate, report the outcome, and don't broadcast at all.
+    Only,
+}
+
+// Which commitment level to submit at and to wait for confirmation at.
+#[derive(Clone, ValueEnum)]
+enum CommitmentArg {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl From<CommitmentArg> for CommitmentConfig {
+    fn from(value: CommitmentArg) -> Self {
+        match value {
+            CommitmentArg::Processed => CommitmentConfig::processed(),
+            CommitmentArg::Confirmed => CommitmentConfig::confirmed(),
+            CommitmentArg::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+}
+
 // Command-line args to parameterize the interest payment client
 #[derive(Parser)]
 struct Args {
@@ -21,19 +71,111 @@ struct Args {
     #[clap(short, long)]
     url: Option<String>,
 
-    // Pass in the vault to which the interest is to be paid into the -v flag
+    // Pass in the vault to which the interest is to be paid into the -v flag. Required unless
+    // `--config` is used to drive many vaults from one process.
     #[clap(short, long)]
-    vault: Pubkey,
+    vault: Option<Pubkey>,
 
     // Pass the duration in days on how frequently should the call be made.
-    // Defaults to 30 days
+    // Defaults to 30 days. Used for `--vault`, and as the fallback for any `--config` entry
+    // that doesn't set its own interval.
     #[clap(short, long, default_value = "30")]
     duration: u16,
 
+    /// Load a list of vaults to manage from a JSON config file instead of a single `--vault`.
+    /// Each vault runs on its own timer/task, sharing one RPC client and connection cache.
+    #[clap(long)]
+    config: Option<String>,
+
+    /// How to broadcast the signed transaction. `tpu` pushes directly to the slot leaders
+    /// instead of going through the RPC node's forwarding path.
+    #[clap(long, value_enum, default_value = "rpc")]
+    submit: SubmitMode,
+
+    /// Websocket endpoint used to confirm transactions via `signatureSubscribe` (in both submit
+    /// modes) and, with `--submit=tpu`, by the TPU client to track the leader schedule.
+    /// Defaults to the standard `ws(s)://` form of the RPC url.
+    #[clap(long)]
+    ws_url: Option<String>,
+
+    /// Commitment level to submit the transaction at and to wait for confirmation at.
+    #[clap(long, value_enum, default_value = "processed")]
+    commitment: CommitmentArg,
+
+    /// Simulate the transaction via `simulateTransaction` before broadcasting. `preflight`
+    /// aborts the iteration if simulation fails; `only` reports the outcome and never
+    /// broadcasts.
+    #[clap(long, value_enum, default_value = "off")]
+    simulate: SimulateMode,
+
+    /// Durable nonce account to use as the transaction's blockhash instead of fetching a
+    /// fresh one each iteration. Lets the payment be pre-built and broadcast later without
+    /// racing a ~150-block blockhash expiry.
+    #[clap(long)]
+    nonce: Option<Pubkey>,
+
+    /// Keypair authorized over the nonce account in `--nonce`. Defaults to the interest
+    /// payer keypair when not supplied.
+    #[clap(long)]
+    nonce_authority: Option<String>,
+
+    /// How many times to re-sign and resend a dropped/expired transaction before giving up
+    /// on the current interval. Backs off exponentially between attempts.
+    #[clap(long, default_value = "5")]
+    max_retries: u32,
+
+    /// How long to wait for a websocket confirmation before treating the transaction as
+    /// dropped. Defaults to roughly the lifetime of a recent blockhash.
+    #[clap(long, default_value = "60")]
+    confirm_timeout_secs: u64,
+
     /// Defaults to your Solana CLI config file. You can optionally include your keypair path.
     keypair_path: Option<String>,
 }
 
+// One entry in a `--config` file: a vault to manage plus optional per-vault overrides.
+#[derive(Deserialize)]
+struct VaultEntry {
+    vault: Pubkey,
+    #[serde(default)]
+    duration: Option<u16>,
+    #[serde(default)]
+    keypair_path: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct VaultsConfig {
+    vaults: Vec<VaultEntry>,
+}
+
+// A single vault to drive on its own task: which account, how often, and which keypair pays.
+struct VaultJob {
+    label: String,
+    vault: Pubkey,
+    duration: u16,
+    payer_keypair: Keypair,
+}
+
+// State shared by every vault task: one RPC client/connection cache for the whole process,
+// plus the submission settings that apply uniformly across vaults.
+struct SharedContext<P, M, C>
+where
+    P: ConnectionPool<NewConnectionConfig = C>,
+    M: ConnectionManager<ConnectionPool = P, NewConnectionConfig = C>,
+    C: NewConnectionConfig,
+{
+    network_url: String,
+    ws_url: String,
+    commitment: CommitmentConfig,
+    simulate: SimulateMode,
+    tpu_client: Option<Arc<TpuClient<P, M, C>>>,
+    nonce: Option<Pubkey>,
+    nonce_authority_keypair: Option<Keypair>,
+    max_retries: u32,
+    confirm_timeout: std::time::Duration,
+    rpc_client: RpcClient,
+}
+
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 
 pub const VAULTS_PROGRAM_ID: Pubkey = pubkey!("5j3KuMK2u7KFtoEwiLTexUeooHq5NPQX96rYp5dhuze9");
@@ -42,6 +184,11 @@ pub fn get_keypair_from_path(path: &str) -> anyhow::Result<Keypair> {
     read_keypair_file(&*shellexpand::tilde(path)).map_err(|e| anyhow!(e.to_string()))
 }
 
+// Keypair isn't Clone, so sharing one payer across several vault tasks goes through its bytes.
+pub fn clone_keypair(keypair: &Keypair) -> anyhow::Result<Keypair> {
+    Keypair::from_bytes(&keypair.to_bytes()).map_err(|e| anyhow!(e.to_string()))
+}
+
 pub fn get_network(network_str: &str) -> &str {
     match network_str {
         "devnet" | "dev" | "d" => "https://api.devnet.solana.com",
@@ -51,6 +198,330 @@ pub fn get_network(network_str: &str) -> &str {
     }
 }
 
+// Mirrors how the standard Solana CLI tooling derives a websocket url from an RPC url
+// when the caller doesn't supply one explicitly (http -> ws, https -> wss, and an explicit
+// port is bumped by one, e.g. localhost:8899 -> localhost:8900).
+pub fn derive_ws_url(rpc_url: &str) -> String {
+    Config::compute_websocket_url(rpc_url)
+}
+
+// Waits on a websocket `signatureSubscribe` notification instead of polling the RPC node,
+// so the caller finds out the moment the validator reports a result at `commitment`. Bounded
+// by `timeout`, since a genuinely dropped transaction never gets a notification and the
+// baseline's spinner-based confirm gave up once the blockhash expired rather than hanging.
+pub async fn confirm_signature(
+    ws_url: &str,
+    signature: &Signature,
+    commitment: CommitmentConfig,
+    timeout: std::time::Duration,
+) -> anyhow::Result<()> {
+    let wait_for_notification = async {
+        let pubsub_client = PubsubClient::new(ws_url).await?;
+
+        let (mut notifications, unsubscribe) = pubsub_client
+            .signature_subscribe(
+                signature,
+                Some(RpcSignatureSubscribeConfig {
+                    commitment: Some(commitment),
+                    enable_received_notification: Some(false),
+                }),
+            )
+            .await?;
+
+        let result = notifications.next().await;
+        unsubscribe().await;
+
+        anyhow::Ok(result)
+    };
+
+    match tokio::time::timeout(timeout, wait_for_notification).await {
+        Ok(Ok(Some(response))) => match response.value {
+            RpcSignatureResult::ProcessedSignature(signature_result) => {
+                match signature_result.err {
+                    Some(err) => Err(anyhow!("Transaction {} failed: {}", signature, err)),
+                    None => Ok(()),
+                }
+            }
+            RpcSignatureResult::ReceivedSignature(_) => {
+                Err(anyhow!("Transaction {} was only received, not processed", signature))
+            }
+        },
+        Ok(Ok(None)) => Err(anyhow!("Signature subscription for {} closed with no notification", signature)),
+        Ok(Err(err)) => Err(err),
+        Err(_) => Err(anyhow!("Transaction {} not confirmed within {:?}; likely dropped", signature, timeout)),
+    }
+}
+
+// Runs `simulateTransaction` and prints logs/err/compute units so an operator can validate
+// their vault/keypair/RPC setup (or watch TopupInterest's compute usage) without spending fees.
+// Returns `Ok(())` when the simulation reported no error.
+pub async fn simulate_and_report(rpc_client: &RpcClient, transaction: &Transaction) -> anyhow::Result<()> {
+    let response = rpc_client.simulate_transaction(transaction).await?;
+    let result = response.value;
+
+    if let Some(logs) = &result.logs {
+        for log in logs {
+            println!("  sim log: {}", log);
+        }
+    }
+    if let Some(units_consumed) = result.units_consumed {
+        println!("  sim units consumed: {}", units_consumed);
+    }
+    if let Some(accounts) = &result.accounts {
+        println!("  sim returned {} account(s)", accounts.len());
+    }
+
+    match result.err {
+        Some(err) => Err(anyhow!("Simulation failed: {}", err)),
+        None => Ok(()),
+    }
+}
+
+// Reads the blockhash durably stored in a nonce account, as set by the most recent
+// `advance_nonce_account` to land. Used in place of `get_latest_blockhash` so a transaction
+// can be signed once and broadcast later, well past the usual ~150-block validity window.
+pub async fn get_nonce_blockhash(rpc_client: &RpcClient, nonce_pubkey: &Pubkey) -> anyhow::Result<Hash> {
+    let nonce_account = rpc_client.get_account(nonce_pubkey).await?;
+
+    let nonce_versions: NonceVersions = nonce_account.state()?;
+
+    match nonce_versions.state() {
+        NonceState::Initialized(data) => Ok(data.blockhash()),
+        NonceState::Uninitialized => Err(anyhow!("Nonce account {} is not initialized", nonce_pubkey)),
+    }
+}
+
+// Sends `transaction` and waits for it to confirm, re-signing and resending on a dropped or
+// expired blockhash up to `max_retries` times with exponential backoff. A transaction that
+// already landed successfully (checked via `get_signature_statuses`) is treated as a success
+// rather than retried again; one that already landed with an on-chain program error is a
+// deterministic failure (resending the identical transaction would just fail identically) and
+// is returned immediately instead of burning retries/backoff on it. A `confirm_signature`
+// timeout (the transaction was dropped rather than rejected outright) surfaces as an `Err`
+// just like a send failure, so it falls into the generic retry path below. Non-nonce
+// transactions get a fresh blockhash on retry once the previous one is no longer valid (and
+// reuse it otherwise); nonce transactions are simply resent, since a durable nonce doesn't
+// expire the way a recent blockhash does.
+async fn send_and_confirm_with_retry<P, M, C>(
+    ctx: &SharedContext<P, M, C>,
+    label: &str,
+    instructions: &[Instruction],
+    payer_pubkey: &Pubkey,
+    signers: &[&dyn Signer],
+    nonce_pubkey: Option<&Pubkey>,
+    mut recent_blockhash: Hash,
+) -> anyhow::Result<Signature>
+where
+    P: ConnectionPool<NewConnectionConfig = C>,
+    M: ConnectionManager<ConnectionPool = P, NewConnectionConfig = C>,
+    C: NewConnectionConfig,
+{
+    let mut attempt = 0;
+
+    loop {
+        let mut transaction = Transaction::new_signed_with_payer(instructions, Some(payer_pubkey), signers, recent_blockhash);
+        transaction.sign(signers, recent_blockhash);
+        let signature = transaction.signatures[0];
+
+        let outcome = match &ctx.tpu_client {
+            Some(tpu_client) => {
+                if tpu_client.send_transaction(&transaction) {
+                    confirm_signature(&ctx.ws_url, &signature, ctx.commitment, ctx.confirm_timeout).await
+                } else {
+                    Err(anyhow!("Transaction {} failed to send via TPU", signature))
+                }
+            }
+            None => {
+                match ctx.rpc_client.send_transaction_with_config(
+                    &transaction,
+                    RpcSendTransactionConfig {
+                        skip_preflight: true,
+                        preflight_commitment: Some(ctx.commitment.commitment),
+                        max_retries: None,
+                        encoding: None,
+                        min_context_slot: None
+                    }
+                ).await {
+                    Ok(_) => confirm_signature(&ctx.ws_url, &signature, ctx.commitment, ctx.confirm_timeout).await,
+                    Err(err) => Err(anyhow!("Transaction {} failed to send: {}", signature, err)),
+                }
+            }
+        };
+
+        if outcome.is_ok() {
+            return Ok(signature);
+        }
+
+        let statuses = ctx.rpc_client.get_signature_statuses(&[signature]).await?;
+        if let Some(Some(status)) = statuses.value.into_iter().next() {
+            if status.satisfies_commitment(ctx.commitment) {
+                match status.err {
+                    None => return Ok(signature),
+                    Some(err) => return Err(anyhow!("Transaction {} failed on-chain: {}", signature, err)),
+                }
+            }
+        }
+
+        if attempt >= ctx.max_retries {
+            return outcome.map(|()| signature).map_err(|err| {
+                anyhow!("Transaction {} exhausted {} retries: {}", signature, ctx.max_retries, err)
+            });
+        }
+
+        if let Some(nonce_pubkey) = nonce_pubkey {
+            println!("[{}] {} (attempt {}/{}); resending against nonce {}", label, outcome.unwrap_err(), attempt + 1, ctx.max_retries, nonce_pubkey);
+        } else {
+            let blockhash_still_valid = ctx.rpc_client.is_blockhash_valid(&recent_blockhash, ctx.commitment).await.unwrap_or(false);
+            if !blockhash_still_valid {
+                recent_blockhash = ctx.rpc_client.get_latest_blockhash().await?;
+            }
+            println!("[{}] {} (attempt {}/{}); retrying with {} blockhash", label, outcome.unwrap_err(), attempt + 1, ctx.max_retries, if blockhash_still_valid { "the same" } else { "a fresh" });
+        }
+
+        let backoff = std::time::Duration::from_millis(500 * 2u64.pow(attempt));
+        tokio::time::sleep(backoff).await;
+
+        attempt += 1;
+    }
+}
+
+// Runs a single pay-interest attempt for one vault: fetch the vault, build+sign the
+// transaction, optionally simulate it, then broadcast and confirm it (with retry). Returns
+// `Err` on a failed RPC call or send instead of taking the whole task down, since callers
+// run this forever and want to log-and-continue rather than abort the vault's schedule. A
+// failed simulation is reported here and ends the iteration with `Ok(())` without broadcasting
+// in `preflight` mode; in `only` mode the iteration always ends after the simulation.
+async fn run_vault_iteration<P, M, C>(
+    label: &str,
+    vault: Pubkey,
+    payer_keypair: &Keypair,
+    ctx: &SharedContext<P, M, C>,
+) -> anyhow::Result<()>
+where
+    P: ConnectionPool<NewConnectionConfig = C>,
+    M: ConnectionManager<ConnectionPool = P, NewConnectionConfig = C>,
+    C: NewConnectionConfig,
+{
+    let rpc_client = &ctx.rpc_client;
+    let vault_account = rpc_client.get_account(&vault).await?;
+
+    let vault_deserialized = Vault::deserialize(vault_account.data.split_at(ANCHOR_DISCRIMINATOR_SIZE).1.borrow_mut())?;
+
+    let token_vault_ac = vault_deserialized.token_vault_ac;
+
+    let token = vault_deserialized.token;
+
+    let token_payer_ac = spl_associated_token_account::get_associated_token_address(&payer_keypair.pubkey(), &token);
+
+    // 8 byte discriminator for instruction TopupInterest
+    let instruction_discriminator: &[u8] = &[196, 215, 224, 233, 237, 212, 2, 56];
+
+    let pay_interest_ix = Instruction::new_with_bytes(
+        VAULTS_PROGRAM_ID,
+        instruction_discriminator,
+        vec![
+            AccountMeta::new(VAULTS_PROGRAM_ID, false),
+            AccountMeta::new(payer_keypair.pubkey(), true),
+            AccountMeta::new_readonly(token, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(token_payer_ac, false),
+            AccountMeta::new(token_vault_ac, false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false)
+        ]
+    );
+
+    let nonce_authority_pubkey = ctx.nonce_authority_keypair
+        .as_ref()
+        .map(Signer::pubkey)
+        .unwrap_or_else(|| payer_keypair.pubkey());
+
+    let (recent_blockhash, instructions) = match &ctx.nonce {
+        Some(nonce_pubkey) => {
+            let nonce_blockhash = get_nonce_blockhash(rpc_client, nonce_pubkey).await?;
+            let advance_nonce_ix = system_instruction::advance_nonce_account(nonce_pubkey, &nonce_authority_pubkey);
+            (nonce_blockhash, vec![advance_nonce_ix, pay_interest_ix])
+        }
+        None => (rpc_client.get_latest_blockhash().await?, vec![pay_interest_ix]),
+    };
+
+    let mut signers: Vec<&dyn Signer> = vec![payer_keypair];
+    if let Some(nonce_authority_keypair) = &ctx.nonce_authority_keypair {
+        if nonce_authority_keypair.pubkey() != payer_keypair.pubkey() {
+            signers.push(nonce_authority_keypair);
+        }
+    }
+
+    let mut transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer_keypair.pubkey()),
+        &signers,
+        recent_blockhash
+    );
+
+    transaction.sign(&signers, recent_blockhash);
+
+    if ctx.simulate != SimulateMode::Off {
+        match simulate_and_report(rpc_client, &transaction).await {
+            Ok(()) => println!("[{}] Simulation succeeded", label),
+            Err(err) => {
+                println!("[{}] {}", label, err);
+                if ctx.simulate == SimulateMode::Preflight {
+                    println!("[{}] Aborting this iteration; skipping broadcast", label);
+                    return Ok(());
+                }
+            }
+        }
+
+        if ctx.simulate == SimulateMode::Only {
+            return Ok(());
+        }
+    }
+
+    let signature = send_and_confirm_with_retry(
+        ctx,
+        label,
+        &instructions,
+        &payer_keypair.pubkey(),
+        &signers,
+        ctx.nonce.as_ref(),
+        recent_blockhash,
+    ).await?;
+
+    println!("[{}] Transaction sig: {}", label, signature);
+
+    Ok(())
+}
+
+// Drives one vault's pay-interest loop forever. A failed iteration (RPC hiccup, simulation
+// error, exhausted retries, ...) is logged and the loop sleeps for the usual interval before
+// trying again, rather than returning an error that would tear down the whole process: with
+// many vaults sharing one process (see `--config`), one vault's trouble should no more stop
+// the others than it would if each vault were its own OS process.
+async fn run_vault_loop<P, M, C>(job: VaultJob, ctx: Arc<SharedContext<P, M, C>>)
+where
+    P: ConnectionPool<NewConnectionConfig = C>,
+    M: ConnectionManager<ConnectionPool = P, NewConnectionConfig = C>,
+    C: NewConnectionConfig,
+{
+    let VaultJob { label, vault, duration, payer_keypair } = job;
+
+    println!("[{}] Payer key: {}", label, payer_keypair.pubkey());
+    println!("[{}] Vault: {}", label, vault);
+    println!("[{}] Runs every {} days", label, duration);
+
+    loop {
+        if let Err(err) = run_vault_iteration(&label, vault, &payer_keypair, &ctx).await {
+            println!("[{}] Iteration failed, will retry next interval. Error: {}", label, err);
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(
+            duration as u64 * 86400_u64 * 1_000
+        ))
+        .await;
+    }
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
@@ -63,81 +534,98 @@ async fn main() -> anyhow::Result<()> {
         None => Config::default()
     };
 
-    let interest_payer_keypair = get_keypair_from_path(&args.keypair_path.unwrap_or(config.keypair_path))?;
-    let network_url = &get_network(&args.url.unwrap_or(config.json_rpc_url)).to_string();
+    let default_payer_keypair = get_keypair_from_path(&args.keypair_path.clone().unwrap_or(config.keypair_path))?;
+    let network_url = get_network(&args.url.clone().unwrap_or(config.json_rpc_url)).to_string();
 
-    let vault = &args.vault;
-    let payment_duration = &args.duration;
+    let default_duration = args.duration;
 
-    println!("Payer key: {}", interest_payer_keypair.pubkey().to_string());
-    println!("Vault: {}", vault.to_string());
-    println!("Runs every {} days", payment_duration);
+    let vault_jobs: Vec<VaultJob> = match &args.config {
+        Some(config_path) => {
+            let config_contents = std::fs::read_to_string(config_path)?;
+            let vaults_config: VaultsConfig = serde_json::from_str(&config_contents)?;
 
-    loop {
-        let rpc_client = RpcClient::new(network_url.to_string());
-        let vault_account = rpc_client.get_account(vault).await?;
-
-        let vault_deserialized = Vault::deserialize(vault_account.data.split_at(ANCHOR_DISCRIMINATOR_SIZE).1.borrow_mut())?;
-
-        let token_vault_ac = vault_deserialized.token_vault_ac;
-
-        let token = vault_deserialized.token;
-
-        let token_payer_ac = spl_associated_token_account::get_associated_token_address(&interest_payer_keypair.pubkey(), &token);
-
-        // 8 byte discriminator for instruction TopupInterest
-        let instruction_discriminator: &[u8] = &[196, 215, 224, 233, 237, 212, 2, 56];
-
-        let pay_interest_ix = Instruction::new_with_bytes(
-            VAULTS_PROGRAM_ID, 
-            instruction_discriminator,
-            vec![
-                AccountMeta::new(VAULTS_PROGRAM_ID, false),
-                AccountMeta::new(interest_payer_keypair.pubkey(), true),
-                AccountMeta::new_readonly(token, false),
-                AccountMeta::new(*vault, false),
-                AccountMeta::new(token_payer_ac, false),
-                AccountMeta::new(token_vault_ac, false),
-                AccountMeta::new_readonly(anchor_spl::token::ID, false),
-                AccountMeta::new_readonly(anchor_lang::system_program::ID, false)   
-            ]
-        );
-
-        let recent_blockhash = rpc_client.get_latest_blockhash().await.unwrap();
-
-        let mut transaction = Transaction::new_signed_with_payer(
-            &[pay_interest_ix],
-            Some(&interest_payer_keypair.pubkey()),
-            &[&interest_payer_keypair],
-            recent_blockhash
-        );
-
-        transaction.sign(&[&interest_payer_keypair], recent_blockhash);
-        
-        let result = rpc_client.send_and_confirm_transaction_with_spinner_and_config(
-            &transaction,
-            CommitmentConfig::processed(),
-            RpcSendTransactionConfig {
-                skip_preflight: true,
-                preflight_commitment: None,
-                max_retries: None,
-                encoding: None,
-                min_context_slot: None
-            }
-        ).await;
+            vaults_config.vaults.into_iter().map(|entry| -> anyhow::Result<VaultJob> {
+                let payer_keypair = match &entry.keypair_path {
+                    Some(path) => get_keypair_from_path(path)?,
+                    None => clone_keypair(&default_payer_keypair)?,
+                };
 
-        match  result {
-            Ok(signature) => {
-                println!("Transaction sig: {}", signature);
-            }
-            Err(err) => {
-                println!("Transaction failed. Error: {}", err);
-            }
+                Ok(VaultJob {
+                    label: entry.vault.to_string(),
+                    vault: entry.vault,
+                    duration: entry.duration.unwrap_or(default_duration),
+                    payer_keypair,
+                })
+            }).collect::<anyhow::Result<Vec<_>>>()?
         }
+        None => {
+            let vault = args.vault.ok_or_else(|| anyhow!("either --vault or --config must be provided"))?;
 
-        tokio::time::sleep(std::time::Duration::from_millis(
-            *payment_duration as u64 * 86400_u64 * 1_000
-        ))
-        .await;
+            vec![VaultJob {
+                label: vault.to_string(),
+                vault,
+                duration: default_duration,
+                payer_keypair: default_payer_keypair,
+            }]
+        }
+    };
+
+    let ws_url = args.ws_url.clone().unwrap_or_else(|| derive_ws_url(&network_url));
+    let commitment: CommitmentConfig = args.commitment.clone().into();
+
+    let nonce_authority_keypair = args.nonce_authority
+        .as_deref()
+        .map(get_keypair_from_path)
+        .transpose()?;
+
+    // Built once and reused across every vault task: TpuClient tracks the leader schedule over
+    // a standing websocket connection, so recreating it per vault (or per loop) would just
+    // churn sockets.
+    let tpu_client = match args.submit {
+        SubmitMode::Tpu => {
+            let blocking_rpc_client = Arc::new(solana_client::rpc_client::RpcClient::new(network_url.clone()));
+            let connection_cache = ConnectionCache::new(DEFAULT_TPU_CONNECTION_POOL_SIZE);
+            Some(Arc::new(TpuClient::new_with_connection_cache(
+                blocking_rpc_client,
+                &ws_url,
+                TpuClientConfig::default(),
+                Arc::new(connection_cache),
+            )?))
+        }
+        SubmitMode::Rpc => None,
+    };
+
+    // Built once and reused across every vault task, instead of each iteration opening its own
+    // HTTP client/connection pool against the same RPC node.
+    let rpc_client = RpcClient::new(network_url.clone());
+
+    let ctx = Arc::new(SharedContext {
+        network_url,
+        ws_url,
+        commitment,
+        simulate: args.simulate,
+        tpu_client,
+        nonce: args.nonce,
+        nonce_authority_keypair,
+        max_retries: args.max_retries,
+        confirm_timeout: std::time::Duration::from_secs(args.confirm_timeout_secs),
+        rpc_client,
+    });
+
+    // Each vault's loop already logs-and-continues on a failed iteration (see
+    // `run_vault_loop`), so normally none of these tasks ever finish. `FuturesUnordered` lets
+    // us notice and log a task that panics without waiting on the others in task-spawn order,
+    // and without one panicking task's join failure tearing down every other vault's loop.
+    let mut tasks: FuturesUnordered<_> = vault_jobs.into_iter().map(|job| {
+        let ctx = Arc::clone(&ctx);
+        tokio::spawn(run_vault_loop(job, ctx))
+    }).collect();
+
+    while let Some(result) = tasks.next().await {
+        if let Err(join_err) = result {
+            println!("A vault task panicked: {}", join_err);
+        }
     }
+
+    Ok(())
 }